@@ -0,0 +1,71 @@
+//! A minimal built-in bitmap font, used by `UnicornHatHd::draw_text` and
+//! `ScrollingText` so callers can show status text without pulling in a
+//! full graphics stack.
+
+/// Width, in columns, of a single glyph.
+pub const FONT_WIDTH: usize = 5;
+/// Height, in rows, of a single glyph.
+pub const FONT_HEIGHT: usize = 7;
+
+/// A single glyph: one column per byte, bit 0 is the top row.
+pub type Glyph = [u8; FONT_WIDTH];
+
+const BLANK: Glyph = [0; FONT_WIDTH];
+
+/// Supported characters and their glyphs. The font only has one case, so
+/// lookups upper-case the input first; space and any other unsupported
+/// character render as blank columns.
+const FONT: [(char, Glyph); 42] = [
+    ('!', [0, 47, 0, 0, 0]),
+    (',', [64, 32, 0, 0, 0]),
+    ('-', [8, 8, 8, 8, 8]),
+    ('.', [0, 96, 96, 0, 0]),
+    ('0', [62, 81, 73, 69, 62]),
+    ('1', [0, 66, 127, 64, 0]),
+    ('2', [66, 97, 81, 73, 70]),
+    ('3', [34, 65, 73, 73, 54]),
+    ('4', [24, 20, 18, 127, 16]),
+    ('5', [39, 69, 69, 69, 57]),
+    ('6', [60, 74, 73, 73, 48]),
+    ('7', [1, 113, 9, 5, 3]),
+    ('8', [54, 73, 73, 73, 54]),
+    ('9', [6, 73, 73, 41, 30]),
+    (':', [0, 54, 54, 0, 0]),
+    ('?', [2, 1, 81, 9, 6]),
+    ('A', [126, 9, 126, 0, 0]),
+    ('B', [127, 73, 73, 73, 54]),
+    ('C', [62, 65, 65, 65, 34]),
+    ('D', [127, 65, 65, 65, 62]),
+    ('E', [127, 73, 73, 73, 65]),
+    ('F', [127, 9, 9, 9, 1]),
+    ('G', [62, 65, 73, 73, 58]),
+    ('H', [127, 8, 8, 8, 127]),
+    ('I', [0, 65, 127, 65, 0]),
+    ('J', [32, 64, 65, 63, 1]),
+    ('K', [127, 8, 20, 34, 65]),
+    ('L', [127, 64, 64, 64, 64]),
+    ('M', [127, 2, 12, 2, 127]),
+    ('N', [127, 2, 4, 8, 127]),
+    ('O', [62, 65, 65, 65, 62]),
+    ('P', [127, 9, 9, 9, 6]),
+    ('Q', [62, 65, 81, 33, 94]),
+    ('R', [127, 9, 25, 41, 70]),
+    ('S', [70, 73, 73, 73, 49]),
+    ('T', [1, 1, 127, 1, 1]),
+    ('U', [63, 64, 64, 64, 63]),
+    ('V', [31, 32, 64, 32, 31]),
+    ('W', [127, 32, 24, 32, 127]),
+    ('X', [99, 20, 8, 20, 99]),
+    ('Y', [3, 4, 120, 4, 3]),
+    ('Z', [97, 81, 73, 69, 67]),
+];
+
+/// Look up the glyph for `c`, falling back to a blank glyph for characters
+/// the font doesn't have.
+pub fn glyph(c: char) -> Glyph {
+    let c = c.to_ascii_uppercase();
+    FONT.iter()
+        .find(|(ch, _)| *ch == c)
+        .map(|(_, glyph)| *glyph)
+        .unwrap_or(BLANK)
+}
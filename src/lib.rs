@@ -1,6 +1,9 @@
 #[cfg(feature = "fake-hardware")]
 extern crate ansi_term;
+extern crate embedded_graphics;
 extern crate failure;
+#[cfg(feature = "fake-hardware")]
+extern crate image;
 extern crate rgb;
 #[cfg(feature = "hardware")]
 extern crate rppal;
@@ -9,25 +12,77 @@ extern crate rppal;
 use ansi_term::ANSIStrings;
 #[cfg(feature = "fake-hardware")]
 use ansi_term::Color::RGB;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Size};
+use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+use embedded_graphics::Pixel;
 use failure::Error;
 #[cfg(feature = "hardware")]
 use rppal::spi::{Bus, Mode, SlaveSelect, Spi};
+#[cfg(feature = "fake-hardware")]
+use std::path::Path;
+
+mod font;
+
+pub use font::{FONT_HEIGHT, FONT_WIDTH};
 
 const LED_SIZE: usize = 16;
 const BUFFER_SIZE: usize = 256 * 3;
 const BLACK: rgb::RGB8 = rgb::RGB8::new(0, 0, 0);
+const DEFAULT_GAMMA: f32 = 2.8;
+const DEFAULT_BRIGHTNESS: f32 = 1.0;
+
+/// Build a `[u8; 256]` lookup table mapping raw 0-255 values to perceived
+/// brightness, so low values don't wash out and colors don't skew.
+fn gamma_table(gamma: f32, brightness: f32) -> [u8; 256] {
+    let mut table = [0u8; 256];
+
+    for (i, entry) in table.iter_mut().enumerate() {
+        *entry = (((i as f32) / 255.0).powf(gamma) * brightness * 255.0).round() as u8;
+    }
+
+    table
+}
+
+/// Buffer rotation applied to the display just before it is written out.
+///
+/// `set_pixel`/`get_pixel` always operate in logical (unrotated) coordinates;
+/// only the final emit order inside [`display`](struct.UnicornHatHd.html#method.display)
+/// changes, so physical orientation can be corrected without the caller
+/// rewriting any coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotate {
+    RotNone,
+    Rot90,
+    Rot180,
+    Rot270,
+}
+
+impl Default for Rotate {
+    fn default() -> Rotate {
+        Rotate::RotNone
+    }
+}
 
 #[cfg(feature = "hardware")]
 /// Provide high-level access to the Unicorn HAT HD.
 pub struct UnicornHatHd {
     leds: [[rgb::RGB8; LED_SIZE]; LED_SIZE],
+    rotation: Rotate,
+    gamma: f32,
+    brightness: f32,
+    gamma_table: [u8; 256],
     spi: Spi,
 }
 
 #[cfg(feature = "fake-hardware")]
 /// Provide high-level access to an emulated Unicorn HAT HD.
 pub struct UnicornHatHd {
-    leds: [u8; (BUFFER_SIZE)],
+    leds: [[rgb::RGB8; LED_SIZE]; LED_SIZE],
+    rotation: Rotate,
+    gamma: f32,
+    brightness: f32,
+    gamma_table: [u8; 256],
 }
 
 impl UnicornHatHd {
@@ -38,6 +93,10 @@ impl UnicornHatHd {
 
         Ok(UnicornHatHd {
             leds: [[rgb::RGB8::new(0, 0, 0); LED_SIZE]; LED_SIZE],
+            rotation: Rotate::default(),
+            gamma: DEFAULT_GAMMA,
+            brightness: DEFAULT_BRIGHTNESS,
+            gamma_table: gamma_table(DEFAULT_GAMMA, DEFAULT_BRIGHTNESS),
             spi,
         })
     }
@@ -48,10 +107,53 @@ impl UnicornHatHd {
     /// `_bus` and `_slave_select` are completely unused by the fake `UnicornHatHd`.
     pub fn new(_bus: Bus, _slave_select: SlaveSelect) -> Result<UnicornHatHd, Error> {
         Ok(UnicornHatHd {
-            leds: [BLACK; BUFFER_SIZE],
+            leds: [[BLACK; LED_SIZE]; LED_SIZE],
+            rotation: Rotate::default(),
+            gamma: DEFAULT_GAMMA,
+            brightness: DEFAULT_BRIGHTNESS,
+            gamma_table: gamma_table(DEFAULT_GAMMA, DEFAULT_BRIGHTNESS),
         })
     }
 
+    /// Set the buffer rotation applied when the display is next written to.
+    pub fn set_rotation(&mut self, r: Rotate) {
+        self.rotation = r;
+    }
+
+    /// Set the gamma value used to correct the non-linear perceived
+    /// brightness of raw RGB values, recomputing the lookup table used by
+    /// `display()`.
+    pub fn set_gamma(&mut self, gamma: f32) {
+        self.gamma = gamma;
+        self.gamma_table = gamma_table(self.gamma, self.brightness);
+    }
+
+    /// Set the global brightness scalar applied on top of gamma correction,
+    /// recomputing the lookup table used by `display()`.
+    pub fn set_brightness(&mut self, brightness: f32) {
+        self.brightness = brightness;
+        self.gamma_table = gamma_table(self.gamma, self.brightness);
+    }
+
+    /// Return `self.leds` with `self.rotation` applied, for use by `display()`.
+    fn rotated_leds(&self) -> [[rgb::RGB8; LED_SIZE]; LED_SIZE] {
+        let mut rotated = [[BLACK; LED_SIZE]; LED_SIZE];
+
+        for (y_src, row) in self.leds.iter().enumerate() {
+            for (x_src, pixel) in row.iter().enumerate() {
+                let (x, y) = match self.rotation {
+                    Rotate::RotNone => (x_src, y_src),
+                    Rotate::Rot90 => (LED_SIZE - 1 - y_src, x_src),
+                    Rotate::Rot180 => (LED_SIZE - 1 - x_src, LED_SIZE - 1 - y_src),
+                    Rotate::Rot270 => (y_src, LED_SIZE - 1 - x_src),
+                };
+                rotated[y][x] = *pixel;
+            }
+        }
+
+        rotated
+    }
+
     #[cfg(feature = "hardware")]
     /// Write the display buffer to the Unicorn HAT HD.
     pub fn display(&mut self) -> Result<(), Error> {
@@ -60,12 +162,12 @@ impl UnicornHatHd {
         let mut res: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
 
         let mut i = 0;
-        for rgb in self.leds.iter().flat_map(|r| r.iter()) {
-            res[i] = rgb.r;
+        for rgb in self.rotated_leds().iter().flat_map(|r| r.iter()) {
+            res[i] = self.gamma_table[rgb.r as usize];
             i += 1;
-            res[i] = rgb.g;
+            res[i] = self.gamma_table[rgb.g as usize];
             i += 1;
-            res[i] = rgb.b;
+            res[i] = self.gamma_table[rgb.b as usize];
             i += 1;
         }
 
@@ -78,11 +180,15 @@ impl UnicornHatHd {
     /// Write the display buffer to the Unicorn HAT HD.
     pub fn display(&mut self) -> Result<(), Error> {
         println!("Unicorn HAT HD:");
-        for y in 0..LED_SIZE {
+        for row in self.rotated_leds().iter() {
             let mut line = vec![];
-            for x in 0..LED_SIZE {
-                let pixel = self.get_pixel(x, y);
-                line.push(RGB(pixel.r, pixel.g, pixel.b).paint("*"));
+            for pixel in row.iter() {
+                let (r, g, b) = (
+                    self.gamma_table[pixel.r as usize],
+                    self.gamma_table[pixel.g as usize],
+                    self.gamma_table[pixel.b as usize],
+                );
+                line.push(RGB(r, g, b).paint("*"));
             }
             println!("{}", ANSIStrings(&line));
         }
@@ -90,6 +196,43 @@ impl UnicornHatHd {
         Ok(())
     }
 
+    #[cfg(feature = "fake-hardware")]
+    /// Render the current buffer to an image file, upscaling each LED to a
+    /// `scale`x`scale` block of pixels so the image is legible at normal
+    /// zoom levels.
+    ///
+    /// The file format follows the extension of `path` (a `.png` path gives
+    /// a PNG); a path with no extension the `image` crate knows is an error.
+    ///
+    /// This applies the same rotation and gamma correction as `display()`,
+    /// so the file matches what the panel would actually show, and is
+    /// useful for documentation or byte-for-byte regression fixtures.
+    pub fn save_frame<P: AsRef<Path>>(&self, path: P, scale: u32) -> Result<(), Error> {
+        let scale = scale.max(1);
+        let size = LED_SIZE as u32 * scale;
+        let mut img = image::RgbImage::new(size, size);
+
+        for (y, row) in self.rotated_leds().iter().enumerate() {
+            for (x, pixel) in row.iter().enumerate() {
+                let color = image::Rgb([
+                    self.gamma_table[pixel.r as usize],
+                    self.gamma_table[pixel.g as usize],
+                    self.gamma_table[pixel.b as usize],
+                ]);
+
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        img.put_pixel(x as u32 * scale + dx, y as u32 * scale + dy, color);
+                    }
+                }
+            }
+        }
+
+        img.save(path)?;
+
+        Ok(())
+    }
+
     /// Set an individual pixel's RGB value.
     ///
     /// The origin (`(0, 0)`) is the top-left of the display, with `x` & `y`
@@ -114,7 +257,107 @@ impl UnicornHatHd {
     /// To clear the display itself, you'll still need to call
     /// [`display`](#method.display) to update the Unicorn HAT HD.
     pub fn clear_pixels(&mut self) {
-        self.leds = [[BLACK; LED_SIZE]; LED_SIZE];
+        self.fill(BLACK);
+    }
+
+    /// Fill the whole buffer with a single color.
+    pub fn fill(&mut self, color: rgb::RGB8) {
+        self.leds = [[color; LED_SIZE]; LED_SIZE];
+    }
+
+    /// Fill a `w`x`h` rectangle with a single color, with `(x, y)` as its
+    /// top-left corner. Clips at the edges of the 16x16 buffer.
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: rgb::RGB8) {
+        for row in self.leds.iter_mut().skip(y).take(h) {
+            for pixel in row.iter_mut().skip(x).take(w) {
+                *pixel = color;
+            }
+        }
+    }
+
+    /// Replace the entire buffer wholesale.
+    pub fn set_all(&mut self, pixels: &[[rgb::RGB8; LED_SIZE]; LED_SIZE]) {
+        self.leds = *pixels;
+    }
+
+    /// Draw `text` into the buffer using the built-in bitmap font, starting
+    /// with the top-left of the first glyph at logical coordinate `(x, y)`.
+    ///
+    /// Glyphs are clipped at the edges of the 16x16 buffer; `x` may be
+    /// negative to draw a message that's partially scrolled off the left
+    /// edge.
+    pub fn draw_text(&mut self, text: &str, x: i32, y: i32, color: rgb::RGB8) {
+        let mut cursor = x;
+
+        for c in text.chars() {
+            let glyph = font::glyph(c);
+
+            for (col, bits) in glyph.iter().enumerate() {
+                let px = cursor + col as i32;
+                if px < 0 || px as usize >= LED_SIZE {
+                    continue;
+                }
+
+                for row in 0..FONT_HEIGHT {
+                    if bits & (1 << row) == 0 {
+                        continue;
+                    }
+
+                    let py = y + row as i32;
+                    if py >= 0 && (py as usize) < LED_SIZE {
+                        self.set_pixel(px as usize, py as usize, color);
+                    }
+                }
+            }
+
+            cursor += FONT_WIDTH as i32 + 1;
+        }
+    }
+}
+
+/// A scrolling marquee message, for use with [`UnicornHatHd::draw_text`].
+///
+/// Holds the message, its color and the current scroll offset, advancing
+/// one column per [`step`](#method.step) call so callers can loop
+/// `step(); hat.clear_pixels(); hat.draw_text(...); hat.display(); sleep(...)` to scroll a
+/// message across the panel.
+pub struct ScrollingText {
+    text: String,
+    color: rgb::RGB8,
+    offset: i32,
+}
+
+impl ScrollingText {
+    /// Create a new `ScrollingText`, starting just off the right edge of
+    /// the display.
+    pub fn new(text: &str, color: rgb::RGB8) -> ScrollingText {
+        ScrollingText {
+            text: text.to_string(),
+            color,
+            offset: LED_SIZE as i32,
+        }
+    }
+
+    /// The message's current horizontal offset, suitable for passing as
+    /// `x` to [`UnicornHatHd::draw_text`].
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// The message's configured color.
+    pub fn color(&self) -> rgb::RGB8 {
+        self.color
+    }
+
+    /// Advance the scroll position by one column, wrapping back to the
+    /// right edge once the message has fully scrolled off the left.
+    pub fn step(&mut self) {
+        self.offset -= 1;
+
+        let width = self.text.chars().count() as i32 * (FONT_WIDTH as i32 + 1);
+        if self.offset < -width {
+            self.offset = LED_SIZE as i32;
+        }
     }
 }
 
@@ -126,3 +369,41 @@ impl Default for UnicornHatHd {
         UnicornHatHd::new(Bus::Spi0, SlaveSelect::Ss0).unwrap()
     }
 }
+
+impl OriginDimensions for UnicornHatHd {
+    fn size(&self) -> Size {
+        Size::new(LED_SIZE as u32, LED_SIZE as u32)
+    }
+}
+
+impl DrawTarget for UnicornHatHd {
+    type Color = Rgb888;
+    type Error = core::convert::Infallible;
+
+    /// Draw individual pixels via the existing logical-coordinate `set_pixel`.
+    ///
+    /// As `embedded-graphics` expects, points that fall outside the 16x16
+    /// buffer are silently dropped rather than treated as an error.
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels {
+            if coord.x < 0 || coord.y < 0 {
+                continue;
+            }
+
+            let (x, y) = (coord.x as usize, coord.y as usize);
+            if x < LED_SIZE && y < LED_SIZE {
+                self.set_pixel(x, y, rgb::RGB8::new(color.r(), color.g(), color.b()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill(rgb::RGB8::new(color.r(), color.g(), color.b()));
+        Ok(())
+    }
+}